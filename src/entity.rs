@@ -1,11 +1,12 @@
 /// 数据实体
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime};
 use duckdb::{Result, Row};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
+use utoipa::ToSchema;
 
 /// 用户实体：同时用于接口的输入与输出。
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
 pub struct User {
     pub id: i64,                    // 编号
     pub name: String,               // 名称
@@ -24,3 +25,12 @@ impl<'stmt> TryFrom<&Row<'stmt>> for User {
         })
     }
 }
+
+/// 分区版本记录：每次对分区的写操作都会产生一个新版本，用于时间旅行查询与回滚。
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct Version {
+    pub partition: String,        // 分区名：YYYYMM
+    pub version: i64,              // 版本号：在分区整个生命周期内单调递增，restore后也不会重置或复用
+    pub operation: String,         // 触发该版本的操作：insert/update/delete/restore from vN
+    pub created_at: NaiveDateTime, // 版本产生时间
+}
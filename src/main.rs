@@ -1,11 +1,17 @@
 mod web;
 mod repository;
 mod entity;
+mod storage;
+mod mock;
 
 use crate::web::start;
 use anyhow::Result;
+use tracing_subscriber::EnvFilter;
 
 #[actix_web::main]
 async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .init(); // 初始化结构化日志：可通过RUST_LOG环境变量调整级别
     start().await // 启动Web服务
 }
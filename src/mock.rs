@@ -0,0 +1,182 @@
+/// 内存版存储后端：仅用于测试HTTP层，不落盘、不依赖`repositories/`目录。
+use crate::entity::{User, Version};
+use crate::repository::window_start;
+use crate::storage::Storage;
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use std::sync::Mutex;
+
+/// 一条内存版本记录：`snapshot`保存该版本产生时分区内的全部数据，供`checkpoint`/`restore`直接取用。
+struct VersionRecord {
+    partition: String,
+    version: i64,
+    operation: String,
+    created_at: NaiveDateTime,
+    snapshot: Vec<User>,
+}
+
+/// 用一个受互斥锁保护的`Vec`模拟分区仓库，实现与`Repository`相同的接口但不涉及DuckDB。
+/// 与`Repository`不同的是：`create_user`会替换同`id`的已有用户，而不是再追加一行。
+#[derive(Default)]
+pub struct MockStorage {
+    users: Mutex<Vec<User>>,
+    versions: Mutex<Vec<VersionRecord>>,
+}
+
+impl MockStorage {
+    /// 新建一个空的内存仓库。
+    pub fn new() -> MockStorage {
+        MockStorage::default()
+    }
+
+    /// 为`partition`追加一条版本记录，快照内容取`users`中当前属于该分区的全部行。
+    /// 版本号在分区整个生命周期内单调递增，与`Repository::snapshot`保持相同语义。
+    fn record_version(&self, partition: &str, operation: &str) {
+        let snapshot: Vec<User> = self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|user| user.registered_date.format("%Y%m").to_string() == partition)
+            .cloned()
+            .collect();
+
+        let mut versions = self.versions.lock().unwrap();
+        let next_version = versions.iter().filter(|v| v.partition == partition).map(|v| v.version).max().unwrap_or(0) + 1;
+        versions.push(VersionRecord {
+            partition: partition.to_owned(),
+            version: next_version,
+            operation: operation.to_owned(),
+            created_at: Utc::now().naive_utc(),
+            snapshot,
+        });
+    }
+}
+
+#[async_trait]
+impl Storage for MockStorage {
+    async fn create_user(&self, user: User) -> Result<User> {
+        let partition = user.registered_date.format("%Y%m").to_string();
+        {
+            let mut users = self.users.lock().unwrap();
+            users.retain(|existing| existing.id != user.id);
+            users.push(User { id: user.id, name: user.name.clone(), registered_date: user.registered_date });
+        }
+        self.record_version(&partition, "insert");
+        Ok(user)
+    }
+
+    async fn list_users(&self, page: Option<i64>, limit: Option<i64>) -> Result<Vec<User>> {
+        // 与`Repository::list_users`保持同样的承诺：只返回热数据窗口内的用户，
+        // 否则基于`MockStorage`的测试会对窗口之外的数据产生与真实实现不符的假阳性。
+        let start = window_start();
+        let mut users: Vec<User> = self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|user| user.registered_date >= start)
+            .map(|user| User { id: user.id, name: user.name.clone(), registered_date: user.registered_date })
+            .collect();
+        users.sort_by_key(|user| (user.registered_date, user.id));
+
+        if let Some(limit) = limit {
+            let offset = page
+                .unwrap_or(1)
+                .max(1)
+                .checked_sub(1)
+                .and_then(|page| page.checked_mul(limit))
+                .ok_or_else(|| anyhow!("Invalid pagination parameters: page={:?}, limit={:?}", page, limit))?;
+            users = users.into_iter().skip(offset as usize).take(limit as usize).collect();
+        }
+        Ok(users)
+    }
+
+    async fn get_user(&self, id: i64) -> Result<Option<User>> {
+        Ok(self.users.lock().unwrap().iter().find(|user| user.id == id).map(|user| User {
+            id: user.id,
+            name: user.name.clone(),
+            registered_date: user.registered_date,
+        }))
+    }
+
+    async fn update_user(&self, id: i64, name: String) -> Result<Option<User>> {
+        let updated = {
+            let mut users = self.users.lock().unwrap();
+            match users.iter_mut().find(|user| user.id == id) {
+                Some(user) => {
+                    user.name = name;
+                    Some(User { id: user.id, name: user.name.clone(), registered_date: user.registered_date })
+                }
+                None => None,
+            }
+        };
+        if let Some(user) = &updated {
+            self.record_version(&user.registered_date.format("%Y%m").to_string(), "update");
+        }
+        Ok(updated)
+    }
+
+    async fn delete_user(&self, id: i64) -> Result<bool> {
+        let deleted = {
+            let mut users = self.users.lock().unwrap();
+            let before = users.iter().find(|user| user.id == id).cloned();
+            users.retain(|user| user.id != id);
+            before
+        };
+        if let Some(user) = &deleted {
+            self.record_version(&user.registered_date.format("%Y%m").to_string(), "delete");
+        }
+        Ok(deleted.is_some())
+    }
+
+    async fn archive(&self, _date: NaiveDate) -> Result<()> {
+        Ok(())
+    }
+
+    async fn sweep(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list_versions(&self, month: NaiveDate) -> Result<Vec<Version>> {
+        let partition = month.format("%Y%m").to_string();
+        Ok(self
+            .versions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|version| version.partition == partition)
+            .map(|version| Version {
+                partition: version.partition.clone(),
+                version: version.version,
+                operation: version.operation.clone(),
+                created_at: version.created_at,
+            })
+            .collect())
+    }
+
+    async fn checkpoint(&self, month: NaiveDate, version: i64) -> Result<Vec<User>> {
+        let partition = month.format("%Y%m").to_string();
+        self.versions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|record| record.partition == partition && record.version == version)
+            .map(|record| record.snapshot.clone())
+            .ok_or_else(|| anyhow!("Version {} of partition {} not found", version, partition))
+    }
+
+    async fn restore(&self, month: NaiveDate, version: i64) -> Result<()> {
+        let partition = month.format("%Y%m").to_string();
+        let snapshot = self.checkpoint(month, version).await?;
+
+        let mut users = self.users.lock().unwrap();
+        users.retain(|user| user.registered_date.format("%Y%m").to_string() != partition);
+        users.extend(snapshot);
+        drop(users);
+
+        self.record_version(&partition, &format!("restore from v{}", version));
+        Ok(())
+    }
+}
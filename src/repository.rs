@@ -1,12 +1,17 @@
 /// 分区仓库。
 /// 因为没有专门的『年月』类型，内部使用`NaiveDate`日期作为分区键，其中『日』固定为1号。
-use crate::entity::User;
+use crate::entity::{User, Version};
+use crate::storage::Storage;
 use anyhow::{anyhow, Context, Result};
 use async_duckdb::{Client, ClientBuilder};
-use duckdb::params;
+use async_trait::async_trait;
+use chrono::{Datelike, Local, Months, NaiveDate};
+use duckdb::{params, OptionalExt};
 use glob::{glob, GlobResult};
-use std::fs::create_dir_all;
+use std::fs::{copy, create_dir_all, remove_file};
 use std::path::{Path, PathBuf};
+use tokio::task::spawn_blocking;
+use tracing::instrument;
 
 /// 分区数据库存放目录
 const PARTITION_FOLDER: &str = "repositories";
@@ -28,6 +33,34 @@ fn load() -> Vec<PathBuf> {
         .unwrap_or(Vec::new())
 }
 
+/// 从分区目录下加载已归档的Parquet文件：文件名同样是『年月』格式。
+fn load_archives() -> Vec<PathBuf> {
+    let folder = Path::new(PARTITION_FOLDER);
+    folder
+        .join("[0-9][0-9][0-9][0-9][01][0-9].parquet")
+        .to_str()
+        .map(glob)
+        .and_then(Result::ok)
+        .map(|paths| paths.filter_map(GlobResult::ok))
+        .map(|paths| paths.collect())
+        .unwrap_or(Vec::new())
+}
+
+/// 解析形如`repositories/202401.db`或`repositories/202401.parquet`的路径，返回其分区名（`202401`）。
+fn stem_of(path: &Path) -> Option<String> {
+    path.file_stem().and_then(|stem| stem.to_str()).map(str::to_owned)
+}
+
+/// 将分区名（`YYYYMM`）解析成该月第一天的日期，用于和`WINDOW_MONTHS`比较。
+pub(crate) fn month_of(stem: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("{}01", stem), "%Y%m%d").ok()
+}
+
+/// 热数据窗口的起始月份：早于该月的分区均视为冷数据。
+pub(crate) fn window_start() -> NaiveDate {
+    Local::now().date_naive().with_day(1).unwrap() - Months::new(WINDOW_MONTHS)
+}
+
 /// 内存数据仓库：通过内存数据库ATTACH所有分区数据库，将结果在内存中合并成一张大表。
 pub struct Repository {
     client: Client, // 内存数据库的客户端。
@@ -46,6 +79,7 @@ impl Repository {
     }
 
     /// 若`date`日期所属的分区尚未ATTACH到当前内存数据库中，则尝试ATTACH并初始化。
+    #[instrument(skip(self, path), fields(partition = %path.display()))]
     async fn attach(&self, path: &PathBuf) -> Result<()> {
         let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap().to_owned();
         let name = path.to_str().unwrap().to_owned();
@@ -55,7 +89,9 @@ impl Repository {
                     "select database_name from duckdb_databases() where database_name = ?",
                     params![stem],
                     |_| Ok(())
-                ).or_else(|_| {
+                ).map(|_| tracing::debug!(partition = %stem, "attach cache hit"))
+                .or_else(|_| {
+                    tracing::debug!(partition = %stem, "attach cache miss");
                     connection.execute_batch(&format!(r#"
                         attach if not exists '{path}' as "{stem}";
                         create table if not exists "{stem}".users (
@@ -71,17 +107,178 @@ impl Repository {
         }
     }
 
+    /// 若`stem`分区已被归档为Parquet文件（`.db`文件不存在），将其重新物化为一个新的分区数据库，
+    /// 并删除归档文件，这样后续的`attach`可以像对待热分区一样正常初始化并写入。
+    async fn rematerialize(&self, stem: &str, db_path: &Path) -> Result<()> {
+        let mut parquet_path = PathBuf::from(PARTITION_FOLDER);
+        parquet_path.push(format!("{}.parquet", stem));
+        if !db_path.exists() && parquet_path.exists() {
+            let stem = stem.to_owned();
+            let db = db_path.to_str().unwrap().to_owned();
+            let parquet = parquet_path.to_str().unwrap().to_owned();
+            self.client.conn(move |connection| {
+                connection.execute_batch(&format!(r#"
+                    attach '{db}' as "{stem}";
+                    create table "{stem}".users as select * from read_parquet('{parquet}');
+                "#, db = db, stem = stem, parquet = parquet))
+            }).await.context(format!("Rematerialize partition {} failed", stem))?;
+            remove_file(&parquet_path).context(format!("Remove archived file {} failed", parquet_path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// 在所有ATTACHed热分区中定位`id`所在的分区名（`YYYYMM`）；未找到时返回`None`。
+    /// 分区键由`registered_date`决定，调用方只持有`id`时只能按此逐个分区扫描。
+    #[instrument(skip(self))]
+    async fn locate(&self, id: i64) -> Result<Option<String>> {
+        self.client.conn(move |connection| {
+            let sql: Option<String> = connection.query_row(r#"
+              select
+                string_agg(
+                  format('select ''{}'' as partition from "{}".users where id = {}', database_name, database_name, ?),
+                  ' union all '
+                )
+              from duckdb_databases()
+              where database_name ~ '\d{6}'
+            "#, params![id], |row| row.get(0)).ok();
+
+            match sql.filter(|sql| !sql.is_empty()) {
+                Some(sql) => {
+                    tracing::debug!(fan_out_sql = %sql, "locate union query");
+                    connection
+                        .query_row(&format!("select partition from ({}) limit 1", sql), [], |row| row.get(0))
+                        .optional()
+                }
+                None => Ok(None),
+            }
+        }).await.context("Locate partition failed")
+    }
+
+    /// 在已归档为Parquet的冷分区中定位`id`所在的分区名；仅用于`locate`在热分区找不到时的兜底，
+    /// 避免`sweep`归档掉的老用户在ID查询时被误判成不存在。
+    #[instrument(skip(self))]
+    async fn locate_archived(&self, id: i64) -> Result<Option<String>> {
+        let archives: Vec<(String, String)> = load_archives()
+            .into_iter()
+            .filter_map(|path| Some((stem_of(&path)?, path.to_str()?.to_owned())))
+            .collect();
+        if archives.is_empty() {
+            return Ok(None);
+        }
+        self.client.conn(move |connection| {
+            for (stem, path) in &archives {
+                let found: Option<i64> = connection
+                    .query_row(&format!("select id from read_parquet('{}') where id = ?", path), params![id], |row| row.get(0))
+                    .optional()?;
+                if found.is_some() {
+                    return Ok(Some(stem.clone()));
+                }
+            }
+            Ok(None)
+        }).await.context("Locate archived partition failed")
+    }
+
+    /// 定位`id`所在分区，热分区找不到时退回扫描已归档的冷分区。
+    /// 返回的`bool`标记该分区当前是否处于归档（冷）状态：调用方据此决定是否需要先`rematerialize`才能写入。
+    #[instrument(skip(self))]
+    async fn locate_any(&self, id: i64) -> Result<Option<(String, bool)>> {
+        if let Some(stem) = self.locate(id).await? {
+            return Ok(Some((stem, false)));
+        }
+        Ok(self.locate_archived(id).await?.map(|stem| (stem, true)))
+    }
+
+    /// 确保版本元数据表`_versions`存在：记录每个分区的版本号、操作类型和产生时间。
+    /// `unique(partition, version)`保证并发快照不会在同一分区下产生重复版本号。
+    async fn ensure_versions_table(&self) -> Result<()> {
+        self.client.conn(|connection| {
+            connection.execute_batch(r#"
+                create table if not exists _versions (
+                  partition text not null,
+                  version bigint not null,
+                  operation text not null,
+                  created_at timestamp not null default current_timestamp,
+                  unique (partition, version),
+                );
+            "#)
+        }).await.context("Create versions table failed")
+    }
+
+    /// 为`stem`分区生成一个新版本快照：先CHECKPOINT落盘，再把`.db`文件复制为
+    /// `repositories/{stem}.v{N}.db`，并在`_versions`中追加一行，返回新版本号。
+    ///
+    /// Actix可能有多个worker并发调用本方法操作同一分区，读取下一个版本号、CHECKPOINT、
+    /// 复制快照文件、写入`_versions`这几步跨越多次独立的`conn()`调用，无法整体做成一个事务；
+    /// 因此依赖`_versions`上的`unique(partition, version)`约束兜底——若两次调用读到相同的
+    /// 版本号，后插入的一方会因违反唯一约束而失败，此时重新读取版本号并重试整个流程。
+    #[instrument(skip(self))]
+    async fn snapshot(&self, stem: &str, operation: &str) -> Result<i64> {
+        self.ensure_versions_table().await?;
+
+        loop {
+            let name = stem.to_owned();
+            let next_version: i64 = self.client.conn(move |connection| {
+                connection.query_row(
+                    "select coalesce(max(version), 0) + 1 from _versions where partition = ?",
+                    params![name],
+                    |row| row.get(0),
+                )
+            }).await.context("Compute next version failed")?;
+
+            let mut db_path = PathBuf::from(PARTITION_FOLDER);
+            db_path.push(format!("{}.db", stem));
+            let mut snapshot_path = PathBuf::from(PARTITION_FOLDER);
+            snapshot_path.push(format!("{}.v{}.db", stem, next_version));
+
+            let name = stem.to_owned();
+            self.client.conn(move |connection| connection.execute_batch(&format!(r#"checkpoint "{}";"#, name)))
+                .await
+                .context(format!("Checkpoint partition {} failed", stem))?;
+
+            // 复制整份`.db`文件是阻塞IO，交给`spawn_blocking`以免占用异步运行时的工作线程。
+            spawn_blocking(move || copy(&db_path, &snapshot_path))
+                .await
+                .context("Snapshot copy task panicked")?
+                .context(format!("Snapshot partition {} to version {} failed", stem, next_version))?;
+
+            let name = stem.to_owned();
+            let op = operation.to_owned();
+            let inserted = self.client.conn(move |connection| {
+                connection.execute(
+                    "insert into _versions (partition, version, operation) values (?, ?, ?)",
+                    params![name, next_version, op],
+                )
+            }).await;
+
+            match inserted {
+                Ok(_) => return Ok(next_version),
+                Err(error) if error.to_string().to_lowercase().contains("constraint") => {
+                    tracing::debug!(partition = %stem, version = next_version, "version number race, retrying snapshot");
+                    continue;
+                }
+                Err(error) => return Err(error).context("Record version failed"),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Storage for Repository {
     /// 将用户信息保存到分区数据库中。
-    pub async fn create_user(&self, user: User) -> Result<User> {
+    /// 若目标分区此前已被归档，先将其从Parquet文件重新物化，再写入新记录。
+    #[instrument(skip(self, user), fields(partition = %user.registered_date.format("%Y%m")))]
+    async fn create_user(&self, user: User) -> Result<User> {
+        let stem = user.registered_date.format("%Y%m").to_string();
         let mut path = PathBuf::from(PARTITION_FOLDER);
-        path.push(user.registered_date.format("%Y%m.db").to_string());
+        path.push(format!("{}.db", stem));
+        self.rematerialize(&stem, &path).await?;
         self.attach(&path).await?;
 
         let sql = user
             .registered_date
             .format("insert into \"%Y%m\".users values (?, ?, ?) returning *")
             .to_string();
-        self.client.conn(move |connection| {
+        let created = self.client.conn(move |connection| {
             connection.query_row(
                 sql.as_str(),
                 params![&user.id, &user.name, &user.registered_date],
@@ -89,13 +286,126 @@ impl Repository {
             )
         })
         .await
-        .context("Failed to create user")
+        .context("Failed to create user")?;
+
+        self.snapshot(&stem, "insert").await?;
+        Ok(created)
     }
 
-    /// 返回热数据集中的用户列表。
-    pub async fn list_users(&self) -> Result<Vec<User>> {
-        self.client.conn(|connection| {
-            connection.query_row(r#"
+    /// 按编号查询单个用户；不存在时返回`None`。
+    /// `id`若落在已归档的冷分区，直接通过`read_parquet`只读查询，不需要重新物化。
+    #[instrument(skip(self))]
+    async fn get_user(&self, id: i64) -> Result<Option<User>> {
+        let (stem, archived) = match self.locate_any(id).await? {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        if archived {
+            let mut parquet_path = PathBuf::from(PARTITION_FOLDER);
+            parquet_path.push(format!("{}.parquet", stem));
+            let parquet = parquet_path.to_str().unwrap().to_owned();
+            return self.client.conn(move |connection| {
+                connection
+                    .query_row(&format!("select * from read_parquet('{}') where id = ?", parquet), params![id], |row| {
+                        User::try_from(row)
+                    })
+                    .optional()
+            }).await.context("Get archived user failed");
+        }
+        self.client.conn(move |connection| {
+            connection
+                .query_row(&format!(r#"select * from "{}".users where id = ?"#, stem), params![id], |row| {
+                    User::try_from(row)
+                })
+                .optional()
+        }).await.context("Get user failed")
+    }
+
+    /// 更新用户姓名；不存在时返回`None`。
+    /// `id`若落在已归档的冷分区，先将该分区重新物化（与`create_user`相同的机制）才能执行UPDATE。
+    #[instrument(skip(self, name))]
+    async fn update_user(&self, id: i64, name: String) -> Result<Option<User>> {
+        let (stem, archived) = match self.locate_any(id).await? {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        if archived {
+            let mut db_path = PathBuf::from(PARTITION_FOLDER);
+            db_path.push(format!("{}.db", stem));
+            self.rematerialize(&stem, &db_path).await?;
+            self.attach(&db_path).await?;
+        }
+        let partition = stem.clone();
+        let updated = self.client.conn(move |connection| {
+            connection
+                .query_row(
+                    &format!(r#"update "{}".users set name = ? where id = ? returning *"#, stem),
+                    params![name, id],
+                    |row| User::try_from(row),
+                )
+                .optional()
+        }).await.context("Update user failed")?;
+
+        if updated.is_some() {
+            self.snapshot(&partition, "update").await?;
+        }
+        Ok(updated)
+    }
+
+    /// 删除用户，返回是否存在并删除成功。
+    /// `id`若落在已归档的冷分区，先将该分区重新物化（与`create_user`相同的机制）才能执行DELETE。
+    #[instrument(skip(self))]
+    async fn delete_user(&self, id: i64) -> Result<bool> {
+        let (stem, archived) = match self.locate_any(id).await? {
+            Some(found) => found,
+            None => return Ok(false),
+        };
+        if archived {
+            let mut db_path = PathBuf::from(PARTITION_FOLDER);
+            db_path.push(format!("{}.db", stem));
+            self.rematerialize(&stem, &db_path).await?;
+            self.attach(&db_path).await?;
+        }
+        let partition = stem.clone();
+        let deleted = self.client.conn(move |connection| {
+            connection.execute(&format!(r#"delete from "{}".users where id = ?"#, stem), params![id])
+        }).await.context("Delete user failed").map(|count| count > 0)?;
+
+        if deleted {
+            self.snapshot(&partition, "delete").await?;
+        }
+        Ok(deleted)
+    }
+
+    /// 返回热数据集中的用户列表，按`page`/`limit`分页（两者均省略时返回全部热数据）。
+    /// 若归档目录中存在落在热数据窗口内的Parquet文件（例如`sweep`尚未清理的边界分区），
+    /// 通过`read_parquet`将其与ATTACH的分区一并UNION，整个过程无需重新ATTACH任何数据库。
+    #[instrument(skip(self))]
+    async fn list_users(&self, page: Option<i64>, limit: Option<i64>) -> Result<Vec<User>> {
+        let start = window_start();
+        let archive_clauses: Vec<String> = load_archives()
+            .into_iter()
+            .filter_map(|path| {
+                let stem = stem_of(&path)?;
+                let month = month_of(&stem)?;
+                (month >= start).then(|| format!("select * from read_parquet('{}')", path.to_str()?))
+            })
+            .collect();
+
+        // `page`/`limit`来自客户端，未经校验的乘法可能溢出，必须用`checked_mul`拒绝非法分页参数。
+        let offset = match limit {
+            Some(limit) => Some(
+                page.unwrap_or(1)
+                    .max(1)
+                    .checked_sub(1)
+                    .and_then(|page| page.checked_mul(limit))
+                    .ok_or_else(|| anyhow!("Invalid pagination parameters: page={:?}, limit={:?}", page, limit))?,
+            ),
+            None => None,
+        };
+
+        self.client.conn(move |connection| {
+            let attached_sql: Option<String> = connection.query_row(r#"
               select
                 string_agg(
                   format('select * from "{}".users', database_name),
@@ -111,15 +421,164 @@ impl Repository {
                   month(current_date),
                   1
                 ) - interval (? || ' months') <= strptime(database_name, '%Y%m')
-            "#, params![WINDOW_MONTHS], |row| row.get(0)).and_then(|sql: String| {
-                let mut statement = connection.prepare(sql.as_str())?;
+            "#, params![WINDOW_MONTHS], |row| row.get(0)).ok();
+
+            let mut clauses: Vec<String> = attached_sql.into_iter().collect();
+            clauses.extend(archive_clauses);
+            if clauses.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let mut sql = clauses.join(" union all ");
+            if let Some(limit) = limit {
+                sql = format!("select * from ({}) order by registered_date, id limit {} offset {}", sql, limit, offset.unwrap());
+            }
+            tracing::debug!(fan_out_sql = %sql, "list_users union query");
+
+            let mut statement = connection.prepare(&sql)?;
+            let mut rows = statement.query([])?;
+            let mut users = Vec::new();
+            while let Some(row) = rows.next()? {
+                users.push(User::try_from(row)?);
+            }
+            tracing::info!(rows = users.len(), "list_users completed");
+            Ok(users)
+        }).await.context("List users failed")
+    }
+
+    /// 将`date`所在月份的热分区归档：先将其导出为压缩Parquet文件，再DETACH并删除原始`.db`文件。
+    /// 归档后的分区不再占用ATTACH名额与内存：按编号的查询通过`read_parquet`直接读取，
+    /// `list_users`只会UNION落在热数据窗口内的归档文件，窗口之外的归档数据不出现在列表中。
+    #[instrument(skip(self), fields(partition = %date.format("%Y%m")))]
+    async fn archive(&self, date: NaiveDate) -> Result<()> {
+        let stem = date.format("%Y%m").to_string();
+        let mut db_path = PathBuf::from(PARTITION_FOLDER);
+        db_path.push(format!("{}.db", stem));
+        let mut parquet_path = PathBuf::from(PARTITION_FOLDER);
+        parquet_path.push(format!("{}.parquet", stem));
+        let parquet = parquet_path.to_str().unwrap().to_owned();
+
+        let name = stem.clone();
+        tracing::info!(partition = %name, parquet = %parquet, "archiving partition");
+        self.client.conn(move |connection| {
+            connection.execute_batch(&format!(r#"
+                copy (select * from "{stem}".users) to '{parquet}' (format parquet, compression zstd);
+                detach "{stem}";
+            "#, stem = stem, parquet = parquet))
+        }).await.context(format!("Archive partition {} failed", name))?;
+
+        remove_file(&db_path).context(format!("Remove archived database {} failed", db_path.display()))
+    }
+
+    /// 定期清理：将所有早于`WINDOW_MONTHS`热数据窗口的ATTACHed分区归档为Parquet文件。
+    #[instrument(skip(self))]
+    async fn sweep(&self) -> Result<()> {
+        let stems: Vec<String> = self.client.conn(|connection| {
+            let mut statement = connection.prepare(r#"
+                select database_name
+                from duckdb_databases()
+                where
+                  database_name ~ '\d{6}'
+                  and make_date(year(current_date), month(current_date), 1) - interval (? || ' months') > strptime(database_name, '%Y%m')
+            "#)?;
+            let mut rows = statement.query(params![WINDOW_MONTHS])?;
+            let mut names = Vec::new();
+            while let Some(row) = rows.next()? {
+                names.push(row.get::<_, String>(0)?);
+            }
+            Ok(names)
+        }).await.context("List archivable partitions failed")?;
+
+        tracing::info!(partitions = stems.len(), "sweep found archivable partitions");
+        for stem in stems {
+            if let Some(month) = month_of(&stem) {
+                self.archive(month).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 返回`month`分区的版本历史，按版本号升序排列。
+    #[instrument(skip(self))]
+    async fn list_versions(&self, month: NaiveDate) -> Result<Vec<Version>> {
+        let stem = month.format("%Y%m").to_string();
+        self.client.conn(move |connection| {
+            let mut statement = connection.prepare(
+                "select partition, version, operation, created_at from _versions where partition = ? order by version",
+            )?;
+            let mut rows = statement.query(params![stem])?;
+            let mut versions = Vec::new();
+            while let Some(row) = rows.next()? {
+                versions.push(Version {
+                    partition: row.get(0)?,
+                    version: row.get(1)?,
+                    operation: row.get(2)?,
+                    created_at: row.get(3)?,
+                });
+            }
+            Ok(versions)
+        }).await.context("List versions failed")
+    }
+
+    /// 返回`month`分区在`version`版本时刻的全部数据：直接只读ATTACH对应的快照文件查询，不影响实时数据。
+    #[instrument(skip(self))]
+    async fn checkpoint(&self, month: NaiveDate, version: i64) -> Result<Vec<User>> {
+        let stem = month.format("%Y%m").to_string();
+        let mut snapshot_path = PathBuf::from(PARTITION_FOLDER);
+        snapshot_path.push(format!("{}.v{}.db", stem, version));
+        if !snapshot_path.exists() {
+            return Err(anyhow!("Version {} of partition {} not found", version, stem));
+        }
+
+        let alias = format!("{}_v{}", stem, version);
+        let path = snapshot_path.to_str().unwrap().to_owned();
+        self.client.conn(move |connection| {
+            connection.execute_batch(&format!(r#"attach '{path}' as "{alias}" (read_only);"#, path = path, alias = alias))?;
+
+            // 无论读取成功与否都必须DETACH，否则失败的快照读取会留下一个名字含6位年月的挂载，
+            // 被`locate`/`list_users`/`sweep`的`\d{6}`正则误认成真实热分区。
+            let result = (|| {
+                let mut statement = connection.prepare(&format!(r#"select * from "{}".users"#, alias))?;
                 let mut rows = statement.query([])?;
                 let mut users = Vec::new();
                 while let Some(row) = rows.next()? {
                     users.push(User::try_from(row)?);
                 }
                 Ok(users)
-            }).or(Ok(Vec::new()))
-        }).await.context("List users failed")
+            })();
+
+            if let Err(detach_error) = connection.execute_batch(&format!(r#"detach "{}";"#, alias)) {
+                tracing::warn!(partition = %alias, error = %detach_error, "detach after checkpoint failed");
+            }
+            result
+        }).await.context("Checkpoint read failed")
+    }
+
+    /// 将`month`分区回滚到`version`版本：DETACH当前数据库，用快照文件替换`.db`后重新ATTACH，
+    /// 确保内存客户端不会在半写状态下读到文件。版本号序列不会被重置——`_versions`历史照旧保留，
+    /// 回滚本身也会作为一条新版本记录追加在后面，这样`v{N}.db`文件名在整个分区生命周期内
+    /// 不会被复用，不会出现回滚后下一次快照覆盖掉回滚前同名历史快照的问题。
+    #[instrument(skip(self))]
+    async fn restore(&self, month: NaiveDate, version: i64) -> Result<()> {
+        let stem = month.format("%Y%m").to_string();
+        let mut db_path = PathBuf::from(PARTITION_FOLDER);
+        db_path.push(format!("{}.db", stem));
+        let mut snapshot_path = PathBuf::from(PARTITION_FOLDER);
+        snapshot_path.push(format!("{}.v{}.db", stem, version));
+        if !snapshot_path.exists() {
+            return Err(anyhow!("Version {} of partition {} not found", version, stem));
+        }
+
+        let name = stem.clone();
+        self.client.conn(move |connection| connection.execute_batch(&format!(r#"detach if exists "{}";"#, name)))
+            .await
+            .context(format!("Detach partition {} before restore failed", stem))?;
+
+        copy(&snapshot_path, &db_path)
+            .context(format!("Restore partition {} from version {} failed", stem, version))?;
+
+        self.attach(&db_path).await?;
+
+        self.snapshot(&stem, &format!("restore from v{}", version)).await.map(|_| ())
     }
 }
@@ -0,0 +1,40 @@
+/// 可插拔的数据访问层。
+use crate::entity::{User, Version};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+/// 分区存储后端的统一接口：屏蔽具体的分区策略与存储引擎，
+/// 使HTTP层不再依赖`Repository`这一具体实现，便于替换后端或在测试中使用内存版本。
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// 将用户信息保存到对应分区中。
+    async fn create_user(&self, user: User) -> Result<User>;
+
+    /// 返回热数据集中的用户列表，按`page`/`limit`分页。
+    async fn list_users(&self, page: Option<i64>, limit: Option<i64>) -> Result<Vec<User>>;
+
+    /// 按编号查询单个用户；不存在时返回`None`。
+    async fn get_user(&self, id: i64) -> Result<Option<User>>;
+
+    /// 更新用户姓名；不存在时返回`None`。
+    async fn update_user(&self, id: i64, name: String) -> Result<Option<User>>;
+
+    /// 删除用户，返回是否存在并删除成功。
+    async fn delete_user(&self, id: i64) -> Result<bool>;
+
+    /// 将`date`所在月份的分区归档为冷存储。
+    async fn archive(&self, date: NaiveDate) -> Result<()>;
+
+    /// 扫描并归档所有超出热数据窗口的分区。
+    async fn sweep(&self) -> Result<()>;
+
+    /// 返回`month`分区的版本历史，按版本号升序排列。
+    async fn list_versions(&self, month: NaiveDate) -> Result<Vec<Version>>;
+
+    /// 返回`month`分区在`version`版本时刻的全部数据，不影响实时数据。
+    async fn checkpoint(&self, month: NaiveDate, version: i64) -> Result<Vec<User>>;
+
+    /// 将`month`分区回滚到`version`版本。
+    async fn restore(&self, month: NaiveDate, version: i64) -> Result<()>;
+}
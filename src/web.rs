@@ -1,41 +1,425 @@
-use crate::entity::User;
-use crate::repository::Repository;
-use actix_web::web::{get, post, scope, Data, Json};
-use actix_web::{App, HttpResponse, HttpServer, Responder};
+use crate::entity::{User, Version};
+use crate::repository::{month_of, window_start, Repository};
+use crate::storage::Storage;
+use actix_web::web::{delete, get, post, put, scope, Data, Json, Path, Payload, Query};
+use actix_web::{App, HttpRequest, HttpResponse, HttpServer, Responder};
+use actix_ws::Message;
 use anyhow::{Context, Result};
+use futures_util::StreamExt;
+use serde::Deserialize;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing_actix_web::TracingLogger;
+use utoipa::{IntoParams, OpenApi};
+use utoipa_swagger_ui::SwaggerUi;
 
-/// 查询用户：返回注册日期在一年内的用户列表。
-async fn index(repository: Data<Repository>) -> impl Responder {
-    match repository.list_users().await {
+/// HTTP层持有的存储句柄：对具体存储后端（DuckDB分区仓库或内存Mock）保持无感知。
+type AppStorage = Data<Arc<dyn Storage>>;
+
+/// 新用户注册事件的广播通道：`create`成功后发布，每个WebSocket会话各自订阅一份接收端。
+type Hub = broadcast::Sender<User>;
+/// 广播通道缓冲区大小：慢订阅者落后超过这个条数会丢弃旧消息（`RecvError::Lagged`），而不是阻塞写入方。
+const HUB_CAPACITY: usize = 1024;
+
+/// 分页参数：`page`从1开始，省略`limit`时返回热数据窗口内的全部用户。
+#[derive(Deserialize, IntoParams)]
+struct Pagination {
+    page: Option<i64>,
+    limit: Option<i64>,
+}
+
+/// 查询用户：返回注册日期落在最近`WINDOW_MONTHS`个月热数据窗口内的用户列表，支持`page`/`limit`分页；
+/// 窗口外已被归档为Parquet的分区不会被扫描，以保持查询轻量。
+#[utoipa::path(
+    get,
+    path = "/users",
+    params(Pagination),
+    responses(
+        (status = 200, description = "热数据窗口内的用户列表", body = [User]),
+        (status = 400, description = "查询失败"),
+    ),
+)]
+async fn index(repository: AppStorage, pagination: Query<Pagination>) -> impl Responder {
+    match repository.list_users(pagination.page, pagination.limit).await {
         Ok(users) => HttpResponse::Ok().json(users),
         Err(error) => HttpResponse::BadRequest().body(error.to_string()),
     }
 }
 
 /// 创建并返回用户。
-async fn create(repository: Data<Repository>, user: Json<User>) -> impl Responder {
+#[utoipa::path(
+    post,
+    path = "/users",
+    request_body = User,
+    responses(
+        (status = 200, description = "创建成功，返回新建的用户", body = User),
+        (status = 400, description = "创建失败"),
+    ),
+)]
+async fn create(repository: AppStorage, hub: Data<Hub>, user: Json<User>) -> impl Responder {
     match repository.create_user(User {
         id: user.id,
         name: user.name.clone(),
         registered_date: user.registered_date,
     }).await {
-        Ok(user) => HttpResponse::Ok().json(user),
+        Ok(user) => {
+            let _ = hub.send(user.clone()); // 没有订阅者时发送会失败，属于正常情况，忽略即可
+            HttpResponse::Ok().json(user)
+        }
+        Err(error) => HttpResponse::BadRequest().body(error.to_string()),
+    }
+}
+
+/// 按编号查询单个用户。
+#[utoipa::path(
+    get,
+    path = "/users/{id}",
+    responses(
+        (status = 200, description = "查询成功", body = User),
+        (status = 404, description = "用户不存在"),
+        (status = 400, description = "查询失败"),
+    ),
+)]
+async fn show(repository: AppStorage, id: Path<i64>) -> impl Responder {
+    match repository.get_user(id.into_inner()).await {
+        Ok(Some(user)) => HttpResponse::Ok().json(user),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(error) => HttpResponse::BadRequest().body(error.to_string()),
+    }
+}
+
+/// 更新用户姓名的请求体。
+#[derive(Deserialize, serde::Serialize, utoipa::ToSchema)]
+struct UpdateUser {
+    name: String,
+}
+
+/// 更新用户姓名，返回更新后的用户。
+#[utoipa::path(
+    put,
+    path = "/users/{id}",
+    request_body = UpdateUser,
+    responses(
+        (status = 200, description = "更新成功，返回更新后的用户", body = User),
+        (status = 404, description = "用户不存在"),
+        (status = 400, description = "更新失败"),
+    ),
+)]
+async fn update(repository: AppStorage, id: Path<i64>, update: Json<UpdateUser>) -> impl Responder {
+    match repository.update_user(id.into_inner(), update.into_inner().name).await {
+        Ok(Some(user)) => HttpResponse::Ok().json(user),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(error) => HttpResponse::BadRequest().body(error.to_string()),
+    }
+}
+
+/// 删除用户。
+#[utoipa::path(
+    delete,
+    path = "/users/{id}",
+    responses(
+        (status = 204, description = "删除成功"),
+        (status = 404, description = "用户不存在"),
+        (status = 400, description = "删除失败"),
+    ),
+)]
+async fn destroy(repository: AppStorage, id: Path<i64>) -> impl Responder {
+    match repository.delete_user(id.into_inner()).await {
+        Ok(true) => HttpResponse::NoContent().finish(),
+        Ok(false) => HttpResponse::NotFound().finish(),
         Err(error) => HttpResponse::BadRequest().body(error.to_string()),
     }
 }
 
+/// 查询分区的版本历史：`month`为`YYYYMM`格式，例如`202401`。
+#[utoipa::path(
+    get,
+    path = "/partitions/{month}/versions",
+    responses(
+        (status = 200, description = "版本历史，按版本号升序排列", body = [Version]),
+        (status = 400, description = "month格式非法或查询失败"),
+    ),
+)]
+async fn versions(repository: AppStorage, month: Path<String>) -> impl Responder {
+    match month_of(&month) {
+        Some(month) => match repository.list_versions(month).await {
+            Ok(versions) => HttpResponse::Ok().json(versions),
+            Err(error) => HttpResponse::BadRequest().body(error.to_string()),
+        },
+        None => HttpResponse::BadRequest().body("Invalid month, expected YYYYMM"),
+    }
+}
+
+/// 读取分区在某个历史版本时刻的全部数据，不影响实时数据。
+#[utoipa::path(
+    get,
+    path = "/partitions/{month}/versions/{version}",
+    responses(
+        (status = 200, description = "该版本时刻的用户列表", body = [User]),
+        (status = 400, description = "month/version非法，或该版本不存在"),
+    ),
+)]
+async fn checkpoint(repository: AppStorage, path: Path<(String, i64)>) -> impl Responder {
+    let (month, version) = path.into_inner();
+    match month_of(&month) {
+        Some(month) => match repository.checkpoint(month, version).await {
+            Ok(users) => HttpResponse::Ok().json(users),
+            Err(error) => HttpResponse::BadRequest().body(error.to_string()),
+        },
+        None => HttpResponse::BadRequest().body("Invalid month, expected YYYYMM"),
+    }
+}
+
+/// 将分区回滚到某个历史版本。
+#[utoipa::path(
+    post,
+    path = "/partitions/{month}/versions/{version}/restore",
+    responses(
+        (status = 204, description = "回滚成功"),
+        (status = 400, description = "month/version非法，或回滚失败"),
+    ),
+)]
+async fn restore(repository: AppStorage, path: Path<(String, i64)>) -> impl Responder {
+    let (month, version) = path.into_inner();
+    match month_of(&month) {
+        Some(month) => match repository.restore(month, version).await {
+            Ok(()) => HttpResponse::NoContent().finish(),
+            Err(error) => HttpResponse::BadRequest().body(error.to_string()),
+        },
+        None => HttpResponse::BadRequest().body("Invalid month, expected YYYYMM"),
+    }
+}
+
+/// 订阅时的过滤参数：`hot_only`为`true`时只推送注册日期落在当前热数据窗口内的用户，
+/// 复用与`list_users`相同的`WINDOW_MONTHS`窗口逻辑。
+#[derive(Deserialize)]
+struct StreamFilter {
+    hot_only: Option<bool>,
+}
+
+/// 实时推送新注册用户的WebSocket端点：每次`create`成功后，在线会话都会收到一条JSON消息；
+/// 以`hot_only=true`订阅的会话只收到注册日期落在热数据窗口内的用户。
+async fn stream(request: HttpRequest, body: Payload, hub: Data<Hub>, filter: Query<StreamFilter>) -> Result<HttpResponse, actix_web::Error> {
+    let (response, mut session, mut stream) = actix_ws::handle(&request, body)?;
+    let hot_only = filter.hot_only.unwrap_or(false);
+    let mut events = hub.subscribe();
+
+    actix_web::rt::spawn(async move {
+        loop {
+            tokio::select! {
+                event = events.recv() => match event {
+                    Ok(user) if !hot_only || user.registered_date >= window_start() => {
+                        let Ok(json) = serde_json::to_string(&user) else { continue };
+                        if session.text(json).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) => continue,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                },
+                message = stream.next() => match message {
+                    Some(Ok(Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => break,
+                    _ => {}
+                },
+            }
+        }
+        let _ = session.close(None).await;
+    });
+
+    Ok(response)
+}
+
+/// OpenAPI文档：聚合`/users`与`/partitions`相关接口，以及`User`、`UpdateUser`、`Version`模型的schema。
+#[derive(OpenApi)]
+#[openapi(
+    paths(index, create, show, update, destroy, versions, checkpoint, restore),
+    components(schemas(User, UpdateUser, Version)),
+)]
+struct ApiDoc;
+
+/// 后台定时任务：每天扫描一次ATTACHed分区，将超出热数据窗口的分区归档为Parquet文件。
+fn schedule_archive_sweep(repository: AppStorage) {
+    actix_web::rt::spawn(async move {
+        let mut ticker = actix_web::rt::time::interval(std::time::Duration::from_secs(24 * 60 * 60));
+        loop {
+            ticker.tick().await;
+            if let Err(error) = repository.sweep().await {
+                tracing::error!(%error, "Archive sweep failed");
+            }
+        }
+    });
+}
+
 /// 启动Web服务器：监听8080端口。
 pub async fn start() -> Result<()> {
-    let data = Data::new(Repository::new().await?);
+    let storage: Arc<dyn Storage> = Arc::new(Repository::new().await?);
+    let data: AppStorage = Data::new(storage);
+    schedule_archive_sweep(data.clone());
+
+    let (sender, _) = broadcast::channel::<User>(HUB_CAPACITY);
+    let hub: Data<Hub> = Data::new(sender);
+
     HttpServer::new(move || {
-        App::new().app_data(data.clone()).service(
-            scope("/users")
-                .route("", get().to(index))
-                .route("", post().to(create)),
-        )
+        App::new()
+            .wrap(TracingLogger::default())
+            .app_data(data.clone())
+            .app_data(hub.clone())
+            .service(
+                scope("/users")
+                    .route("", get().to(index))
+                    .route("", post().to(create))
+                    .route("/stream", get().to(stream))
+                    .route("/{id}", get().to(show))
+                    .route("/{id}", put().to(update))
+                    .route("/{id}", delete().to(destroy)),
+            )
+            .service(
+                scope("/partitions")
+                    .route("/{month}/versions", get().to(versions))
+                    .route("/{month}/versions/{version}", get().to(checkpoint))
+                    .route("/{month}/versions/{version}/restore", post().to(restore)),
+            )
+            .service(
+                SwaggerUi::new("/swagger-ui/{_:.*}")
+                    .url("/api-docs/openapi.json", ApiDoc::openapi()),
+            )
     })
     .bind(("127.0.0.1", 8080))?
     .run()
     .await
     .context("Start Web Server Failed")
 }
+
+/// HTTP层的单元测试：全部基于`MockStorage`，不触碰`repositories/`目录。
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockStorage;
+    use actix_web::http::StatusCode;
+    use actix_web::test::{call_service, init_service, read_body_json, TestRequest};
+    use chrono::NaiveDate;
+
+    /// 为每个用例准备一套互相独立的`AppStorage`/`Hub`，避免用例间共享状态。
+    fn app_data() -> (AppStorage, Data<Hub>) {
+        let storage: Arc<dyn Storage> = Arc::new(MockStorage::new());
+        let (sender, _) = broadcast::channel::<User>(HUB_CAPACITY);
+        (Data::new(storage), Data::new(sender))
+    }
+
+    fn user(id: i64, name: &str, registered_date: NaiveDate) -> User {
+        User { id, name: name.to_owned(), registered_date }
+    }
+
+    #[actix_web::test]
+    async fn index_returns_empty_list_initially() {
+        let (data, hub) = app_data();
+        let app = init_service(
+            App::new()
+                .app_data(data.clone())
+                .app_data(hub.clone())
+                .service(scope("/users").route("", get().to(index))),
+        )
+        .await;
+
+        let request = TestRequest::get().uri("/users").to_request();
+        let response = call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let users: Vec<User> = read_body_json(response).await;
+        assert!(users.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn create_then_show_round_trips_a_user() {
+        let (data, hub) = app_data();
+        let app = init_service(
+            App::new()
+                .app_data(data.clone())
+                .app_data(hub.clone())
+                .service(
+                    scope("/users")
+                        .route("", post().to(create))
+                        .route("/{id}", get().to(show)),
+                ),
+        )
+        .await;
+
+        let new_user = user(1, "Alice", NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+        let request = TestRequest::post().uri("/users").set_json(&new_user).to_request();
+        let response = call_service(&app, request).await;
+        assert!(response.status().is_success());
+
+        let request = TestRequest::get().uri("/users/1").to_request();
+        let response = call_service(&app, request).await;
+        assert!(response.status().is_success());
+        let found: User = read_body_json(response).await;
+        assert_eq!(found.name, "Alice");
+    }
+
+    #[actix_web::test]
+    async fn show_returns_not_found_for_unknown_user() {
+        let (data, hub) = app_data();
+        let app = init_service(
+            App::new()
+                .app_data(data.clone())
+                .app_data(hub.clone())
+                .service(scope("/users").route("/{id}", get().to(show))),
+        )
+        .await;
+
+        let request = TestRequest::get().uri("/users/404").to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn update_changes_the_name() {
+        let (data, hub) = app_data();
+        data.create_user(user(2, "Bob", NaiveDate::from_ymd_opt(2024, 2, 1).unwrap())).await.unwrap();
+        let app = init_service(
+            App::new()
+                .app_data(data.clone())
+                .app_data(hub.clone())
+                .service(scope("/users").route("/{id}", put().to(update))),
+        )
+        .await;
+
+        let body = UpdateUser { name: "Bobby".to_owned() };
+        let request = TestRequest::put().uri("/users/2").set_json(&body).to_request();
+        let response = call_service(&app, request).await;
+        assert!(response.status().is_success());
+        let updated: User = read_body_json(response).await;
+        assert_eq!(updated.name, "Bobby");
+    }
+
+    #[actix_web::test]
+    async fn destroy_removes_the_user() {
+        let (data, hub) = app_data();
+        data.create_user(user(3, "Carol", NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())).await.unwrap();
+        let app = init_service(
+            App::new()
+                .app_data(data.clone())
+                .app_data(hub.clone())
+                .service(
+                    scope("/users")
+                        .route("/{id}", delete().to(destroy))
+                        .route("/{id}", get().to(show)),
+                ),
+        )
+        .await;
+
+        let request = TestRequest::delete().uri("/users/3").to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let request = TestRequest::get().uri("/users/3").to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}